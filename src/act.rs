@@ -0,0 +1,179 @@
+use std::collections::HashMap;
+
+use reqwest::Client;
+
+use crate::error::FetchError;
+use crate::provider::{StopInfo, TransitProvider};
+
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ActStop {
+    stop_id: u32,
+    name: String,
+    #[allow(dead_code)]
+    latitude: f32,
+    #[allow(dead_code)]
+    longitude: f32,
+    #[allow(dead_code)]
+    order: Option<u32>,
+    #[allow(dead_code)]
+    scheduled_time: Option<String>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ActPrediction {
+    #[allow(dead_code)]
+    stop_id: u32,
+    trip_id: u32,
+    #[allow(dead_code)]
+    vehicle_id: u32,
+    route_name: String,
+    #[allow(dead_code)]
+    predicted_delay_in_seconds: i32,
+    predicted_departure: String,
+    #[allow(dead_code)]
+    prediction_date_time: String,
+}
+
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ActTrip {
+    #[allow(dead_code)]
+    route_id: String,
+    #[allow(dead_code)]
+    direction_id: u32,
+    direction: String,
+    #[allow(dead_code)]
+    schedule_type: String,
+    #[allow(dead_code)]
+    headsign: String,
+    #[allow(dead_code)]
+    destination: String,
+    #[allow(dead_code)]
+    destination2: String,
+    #[allow(dead_code)]
+    trip_start_time: String,
+    trip_id: u32,
+    #[allow(dead_code)]
+    trip_number: u32,
+    #[allow(dead_code)]
+    trip_number2: u32,
+    #[allow(dead_code)]
+    position_number: u32,
+    #[allow(dead_code)]
+    stop_id: u32,
+    #[allow(dead_code)]
+    stop_description: String,
+    #[allow(dead_code)]
+    passing_time: String,
+    #[allow(dead_code)]
+    stop_number: Option<u32>,
+    #[allow(dead_code)]
+    stop_number2: String,
+    #[allow(dead_code)]
+    place_id: Option<String>,
+    #[allow(dead_code)]
+    stop_longitude: f32,
+    #[allow(dead_code)]
+    stop_latitude: f32,
+}
+
+/// AC Transit provider: polls the radius-stops, trips-today, and predictions
+/// endpoints for a fixed list of routes.
+pub struct ActProvider {
+    pub routes: Vec<(String, u32)>,
+    pub token: String,
+}
+
+#[async_trait::async_trait]
+impl TransitProvider for ActProvider {
+    async fn fetch(&self, client: &Client) -> Result<Vec<StopInfo>, FetchError> {
+        let mut stops_info = Vec::new();
+
+        for (route_name, radius) in &self.routes {
+            let radius_url = format!(
+                "https://api.actransit.org/transit/stops/37.855/-122.254/{}/true/{}?token={}",
+                radius, route_name, self.token
+            );
+            let stops: Vec<ActStop> = client.get(radius_url).send().await?.json().await?;
+            for stop in stops {
+                let trips_url = format!(
+                    "https://api.actransit.org/transit/stops/{}/tripstoday?token={}",
+                    stop.stop_id, self.token
+                );
+                let trips: Vec<ActTrip> = client.get(trips_url).send().await?.json().await?;
+                let trips_by_id: HashMap<u32, ActTrip> =
+                    trips
+                        .iter()
+                        .fold(HashMap::new(), |mut acc: HashMap<u32, ActTrip>, trip| {
+                            acc.insert(trip.trip_id, trip.clone());
+                            acc
+                        });
+                let prediction_url = format!(
+                    "https://api.actransit.org/transit/stops/{}/predictions?token={}",
+                    stop.stop_id, self.token
+                );
+                let predictions_check = client.get(prediction_url).send().await?;
+                if predictions_check.status() != 200 {
+                    stops_info.push(StopInfo {
+                        route: route_name.clone(),
+                        name: stop.name.clone(),
+                        direction: None,
+                        prediction: None,
+                    });
+                    continue;
+                }
+                let predictions: Vec<ActPrediction> = predictions_check.json().await?;
+                let mut pushed = false;
+                for prediction in &predictions {
+                    if prediction.route_name != *route_name {
+                        continue;
+                    }
+                    let Some(trip) = trips_by_id.get(&prediction.trip_id) else {
+                        let err = FetchError::MissingTrip {
+                            trip_id: prediction.trip_id,
+                            stop: stop.stop_id,
+                        };
+                        eprintln!("{}: skipping record: {err}", self.name());
+                        continue;
+                    };
+                    let departure = match chrono::NaiveDateTime::parse_from_str(
+                        &prediction.predicted_departure,
+                        "%Y-%m-%dT%H:%M:%S",
+                    ) {
+                        Ok(departure) => departure,
+                        Err(_) => {
+                            let err = FetchError::BadTimestamp {
+                                raw: prediction.predicted_departure.clone(),
+                                stop: stop.stop_id,
+                            };
+                            eprintln!("{}: skipping record: {err}", self.name());
+                            continue;
+                        }
+                    };
+                    pushed = true;
+                    stops_info.push(StopInfo {
+                        route: route_name.clone(),
+                        name: stop.name.clone(),
+                        direction: Some(trip.direction.clone()),
+                        prediction: Some(departure),
+                    });
+                }
+                if !pushed {
+                    stops_info.push(StopInfo {
+                        route: route_name.clone(),
+                        name: stop.name.clone(),
+                        direction: None,
+                        prediction: None,
+                    });
+                }
+            }
+        }
+        Ok(stops_info)
+    }
+
+    fn name(&self) -> &str {
+        "act"
+    }
+}
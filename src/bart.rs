@@ -0,0 +1,111 @@
+use reqwest::Client;
+
+use crate::error::FetchError;
+use crate::provider::{StopInfo, TransitProvider};
+
+#[derive(Clone, Debug, serde::Deserialize)]
+struct BartResponse {
+    root: BartRoot,
+}
+
+#[derive(Clone, Debug, serde::Deserialize)]
+struct BartRoot {
+    station: Vec<BartStation>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize)]
+struct BartStation {
+    name: String,
+    #[allow(dead_code)]
+    abbr: String,
+    etd: Vec<BartEtd>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize)]
+struct BartEtd {
+    destination: String,
+    #[allow(dead_code)]
+    abbreviation: String,
+    estimate: Vec<BartEstimate>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize)]
+struct BartEstimate {
+    minutes: String,
+    #[allow(dead_code)]
+    platform: String,
+    #[allow(dead_code)]
+    direction: String,
+    #[allow(dead_code)]
+    length: String,
+    color: String,
+    #[allow(dead_code)]
+    hexcolor: String,
+    #[allow(dead_code)]
+    bikeflag: String,
+    #[allow(dead_code)]
+    delay: String,
+    #[allow(dead_code)]
+    cancelflag: String,
+    #[allow(dead_code)]
+    dynamicflag: String,
+}
+
+/// BART provider: polls the `etd.aspx` real-time estimated-departure-times
+/// endpoint for a fixed list of origin stations.
+pub struct BartProvider {
+    pub stations: Vec<String>,
+    pub token: String,
+}
+
+#[async_trait::async_trait]
+impl TransitProvider for BartProvider {
+    async fn fetch(&self, client: &Client) -> Result<Vec<StopInfo>, FetchError> {
+        let mut stops_info = Vec::new();
+
+        for station in &self.stations {
+            let url = format!(
+                "https://api.bart.gov/api/etd.aspx?cmd=etd&orig={}&key={}&json=y",
+                station, self.token
+            );
+
+            let resp: BartResponse = client.get(url).send().await?.json().await?;
+
+            for station in resp.root.station {
+                for etd in station.etd {
+                    for estimate in etd.estimate {
+                        let minutes = if estimate.minutes == "Leaving" {
+                            0
+                        } else {
+                            match estimate.minutes.parse::<i64>() {
+                                Ok(minutes) => minutes,
+                                Err(_) => {
+                                    let err = FetchError::UnparseableMinutes {
+                                        raw: estimate.minutes.clone(),
+                                        stop: station.name.clone(),
+                                    };
+                                    eprintln!("{}: skipping record: {err}", self.name());
+                                    continue;
+                                }
+                            }
+                        };
+                        let prediction = chrono::Local::now().naive_local()
+                            + chrono::Duration::minutes(minutes);
+                        stops_info.push(StopInfo {
+                            route: estimate.color,
+                            name: station.name.clone(),
+                            direction: Some(etd.destination.clone()),
+                            prediction: Some(prediction),
+                        });
+                    }
+                }
+            }
+        }
+
+        Ok(stops_info)
+    }
+
+    fn name(&self) -> &str {
+        "bart"
+    }
+}
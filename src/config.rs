@@ -0,0 +1,67 @@
+use std::path::Path;
+
+/// Errors loading the config file or required API tokens.
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigError {
+    #[error("failed to read {path}: {source}")]
+    Read {
+        path: String,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("failed to parse {path}: {source}")]
+    Parse {
+        path: String,
+        #[source]
+        source: toml::de::Error,
+    },
+
+    #[error("missing required environment variable {0}")]
+    MissingToken(&'static str),
+}
+
+#[derive(Clone, Debug, serde::Deserialize)]
+pub struct ActRouteConfig {
+    pub route: String,
+    pub radius: u32,
+}
+
+#[derive(Clone, Debug, serde::Deserialize)]
+pub struct ActConfig {
+    pub routes: Vec<ActRouteConfig>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize)]
+pub struct BartConfig {
+    pub stations: Vec<String>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize)]
+pub struct Config {
+    pub act: ActConfig,
+    pub bart: BartConfig,
+    #[serde(skip)]
+    pub act_token: String,
+    #[serde(skip)]
+    pub bart_token: String,
+}
+
+/// Load `path` as TOML and pull the `ACT_TOKEN`/`BART_TOKEN` API keys out of
+/// the environment, so neither the stop list nor the credentials need to be
+/// baked into the binary.
+pub fn load(path: &Path) -> Result<Config, ConfigError> {
+    let raw = std::fs::read_to_string(path).map_err(|source| ConfigError::Read {
+        path: path.display().to_string(),
+        source,
+    })?;
+    let mut config: Config = toml::from_str(&raw).map_err(|source| ConfigError::Parse {
+        path: path.display().to_string(),
+        source,
+    })?;
+
+    config.act_token = std::env::var("ACT_TOKEN").map_err(|_| ConfigError::MissingToken("ACT_TOKEN"))?;
+    config.bart_token = std::env::var("BART_TOKEN").map_err(|_| ConfigError::MissingToken("BART_TOKEN"))?;
+
+    Ok(config)
+}
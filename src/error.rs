@@ -0,0 +1,18 @@
+/// Errors a `TransitProvider` can hit while fetching predictions.
+///
+/// Distinguishing these from a blanket `Box<dyn Error>` lets callers log and
+/// skip a single bad record instead of aborting the whole poll cycle.
+#[derive(Debug, thiserror::Error)]
+pub enum FetchError {
+    #[error("http request failed: {0}")]
+    Http(#[from] reqwest::Error),
+
+    #[error("stop {stop}: unparseable timestamp {raw:?}")]
+    BadTimestamp { raw: String, stop: u32 },
+
+    #[error("stop {stop}: no trip info found for trip id {trip_id}")]
+    MissingTrip { trip_id: u32, stop: u32 },
+
+    #[error("stop {stop}: unparseable minutes value {raw:?}")]
+    UnparseableMinutes { raw: String, stop: String },
+}
@@ -1,240 +1,152 @@
-use std::{collections::HashMap, time::Duration};
-
+mod act;
+mod bart;
+mod config;
+mod error;
+mod provider;
+mod serve;
+mod watch;
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use clap::{Parser, ValueEnum};
 use reqwest::Client;
 
-const ACT_ROUTES: &[(&str, u32)] = &[("51B", 600), ("27", 800), ("E", 1575)];
-const BART_STOPS: &[&str] = &["ROCK", "ASHB"];
-
-#[derive(Clone, Debug, serde::Deserialize)]
-#[serde(rename_all = "PascalCase")]
-struct ActRoute {
-    route: String,
-    direction: String,
-    destination: String,
-    stops: Vec<ActStop>,
-}
-
-#[derive(Clone, Debug, serde::Deserialize)]
-#[serde(rename_all = "PascalCase")]
-struct ActStop {
-    stop_id: u32,
-    name: String,
-    latitude: f32,
-    longitude: f32,
-    order: Option<u32>,
-    scheduled_time: Option<String>,
-}
+use act::ActProvider;
+use bart::BartProvider;
+use config::Config;
+use provider::{StopInfo, TransitProvider};
+use serve::Departure;
 
-#[derive(Clone, Debug, serde::Deserialize)]
-#[serde(rename_all = "PascalCase")]
-struct ActPrediction {
-    stop_id: u32,
-    trip_id: u32,
-    vehicle_id: u32,
-    route_name: String,
-    predicted_delay_in_seconds: i32,
-    predicted_departure: String,
-    prediction_date_time: String,
-}
+const DEFAULT_WATCH_INTERVAL_SECS: u64 = 30;
+const DEFAULT_SERVE_PORT: u16 = 8080;
+const DEFAULT_CONFIG_PATH: &str = "config.toml";
 
-#[derive(Clone, Debug, serde::Deserialize)]
-#[serde(rename_all = "PascalCase")]
-struct ActTrip {
-    route_id: String,
-    direction_id: u32,
-    direction: String,
-    schedule_type: String,
-    headsign: String,
-    destination: String,
-    destination2: String,
-    trip_start_time: String,
-    trip_id: u32,
-    trip_number: u32,
-    trip_number2: u32,
-    position_number: u32,
-    stop_id: u32,
-    stop_description: String,
-    passing_time: String,
-    stop_number: Option<u32>,
-    stop_number2: String,
-    place_id: Option<String>,
-    stop_longitude: f32,
-    stop_latitude: f32,
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum SortBy {
+    Time,
+    Route,
 }
 
-#[derive(Clone, Debug, serde::Deserialize)]
-struct BartResponse {
-    root: BartRoot,
+/// Live departure predictions for AC Transit and BART stops.
+#[derive(Parser, Debug)]
+#[command(name = "my-little-pal", about = "Live transit departure predictions")]
+struct Cli {
+    /// AC Transit route to watch (repeatable; defaults to all configured routes)
+    #[arg(long = "act-route", value_name = "ROUTE")]
+    act_routes: Vec<String>,
+
+    /// BART origin station to watch (repeatable; defaults to all configured stations)
+    #[arg(long = "bart-stop", value_name = "STOP")]
+    bart_stops: Vec<String>,
+
+    /// Path to the TOML config listing routes, stations, and radii
+    #[arg(long, default_value = DEFAULT_CONFIG_PATH)]
+    config: PathBuf,
+
+    /// Re-poll continuously and print only what changed, instead of printing once and exiting
+    #[arg(long)]
+    watch: bool,
+
+    /// Serve predictions over HTTP instead of printing to stdout
+    #[arg(long)]
+    serve: bool,
+
+    /// Port to bind when --serve is set
+    #[arg(long, default_value_t = DEFAULT_SERVE_PORT)]
+    port: u16,
+
+    /// Emit machine-readable JSON instead of plain text (one-shot mode only)
+    #[arg(long)]
+    json: bool,
+
+    /// Only show departures within this many minutes (one-shot mode only)
+    #[arg(long, value_name = "MINUTES")]
+    within: Option<i64>,
+
+    /// Sort order for one-shot output
+    #[arg(long, value_enum, default_value_t = SortBy::Time)]
+    sort_by: SortBy,
 }
 
-#[derive(Clone, Debug, serde::Deserialize)]
-struct BartRoot {
-    station: Vec<BartStation>,
+fn build_providers(cli: &Cli, config: &Config) -> Vec<Box<dyn TransitProvider>> {
+    let act_routes: Vec<(String, u32)> = config
+        .act
+        .routes
+        .iter()
+        .filter(|route| cli.act_routes.is_empty() || cli.act_routes.contains(&route.route))
+        .map(|route| (route.route.clone(), route.radius))
+        .collect();
+
+    let bart_stops: Vec<String> = config
+        .bart
+        .stations
+        .iter()
+        .filter(|stop| cli.bart_stops.is_empty() || cli.bart_stops.contains(stop))
+        .cloned()
+        .collect();
+
+    vec![
+        Box::new(ActProvider {
+            routes: act_routes,
+            token: config.act_token.clone(),
+        }),
+        Box::new(BartProvider {
+            stations: bart_stops,
+            token: config.bart_token.clone(),
+        }),
+    ]
 }
 
-#[derive(Clone, Debug, serde::Deserialize)]
-struct BartStation {
-    name: String,
-    abbr: String,
-    etd: Vec<BartEtd>,
+fn minutes_away(stop: &StopInfo) -> Option<i64> {
+    stop.prediction
+        .map(|p| (p - chrono::Local::now().naive_local()).num_minutes())
 }
 
-#[derive(Clone, Debug, serde::Deserialize)]
-struct BartEtd {
-    destination: String,
-    abbreviation: String,
-    estimate: Vec<BartEstimate>,
+fn apply_filters(mut stops: Vec<StopInfo>, cli: &Cli) -> Vec<StopInfo> {
+    if let Some(within) = cli.within {
+        stops.retain(|stop| minutes_away(stop).is_some_and(|m| m <= within));
+    }
+    match cli.sort_by {
+        SortBy::Time => stops.sort_by_key(|stop| minutes_away(stop).unwrap_or(i64::MAX)),
+        SortBy::Route => stops.sort_by(|a, b| a.route.cmp(&b.route)),
+    }
+    stops
 }
 
-#[derive(Clone, Debug, serde::Deserialize)]
-struct BartEstimate {
-    minutes: String,
-    platform: String,
-    direction: String,
-    length: String,
-    color: String,
-    hexcolor: String,
-    bikeflag: String,
-    delay: String,
-    cancelflag: String,
-    dynamicflag: String,
-}
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let cli = Cli::parse();
+    let config = config::load(&cli.config)?;
+    let client = Client::builder().timeout(Duration::from_secs(30)).build()?;
+
+    if cli.serve {
+        let state = serve::AppState {
+            client,
+            providers: build_providers(&cli, &config),
+        };
+        return serve::run(state, cli.port).await;
+    }
 
-#[derive(Clone, Debug)]
-struct StopInfo {
-    route: String,
-    name: String,
-    direction: Option<String>,
-    prediction: Option<chrono::NaiveDateTime>,
-}
+    let providers = build_providers(&cli, &config);
 
-async fn fetch_bart() -> Result<Vec<StopInfo>, Box<dyn std::error::Error>> {
-    let token = "";
-    let client = Client::builder()
-        .timeout(Duration::from_secs(30)) // Set a 30-second request timeout
-        .build()?; // Build the client
-
-    let mut stops_info = Vec::new();
-
-    for station in BART_STOPS {
-        let url = format!(
-            "https://api.bart.gov/api/etd.aspx?cmd=etd&orig={}&key={}&json=y",
-            station, token
-        );
-
-        let resp: BartResponse = client.get(url).send().await?.json().await?;
-
-        for station in resp.root.station {
-            for etd in station.etd {
-                for estimate in etd.estimate {
-                    let minutes = if estimate.minutes == "Leaving" {
-                        0
-                    } else {
-                        estimate.minutes.parse::<i64>()?
-                    };
-                    let prediction =
-                        chrono::Local::now().naive_local() + chrono::Duration::minutes(minutes);
-                    stops_info.push(StopInfo {
-                        route: estimate.color,
-                        name: station.name.clone(),
-                        direction: Some(etd.destination.clone()),
-                        prediction: Some(prediction),
-                    });
-                }
-            }
-        }
+    if cli.watch {
+        let interval = Duration::from_secs(DEFAULT_WATCH_INTERVAL_SECS);
+        return watch::run(&providers, &client, interval).await;
     }
 
-    Ok(stops_info)
-}
+    let mut stops = Vec::new();
+    for provider in &providers {
+        stops.extend(provider.fetch(&client).await?);
+    }
+    let stops = apply_filters(stops, &cli);
 
-async fn fetch_act() -> Result<Vec<StopInfo>, Box<dyn std::error::Error>> {
-    let token = "";
-
-    let client = Client::builder()
-        .timeout(Duration::from_secs(30)) // Set a 30-second request timeout
-        .build()?; // Build the client
-
-    let mut stops_info = Vec::new();
-
-    for route in ACT_ROUTES {
-        let radius_url = format!(
-            "https://api.actransit.org/transit/stops/37.855/-122.254/{}/true/{}?token={}",
-            route.1, route.0, token
-        );
-        let stops: Vec<ActStop> = client.get(radius_url).send().await?.json().await?;
-        for stop in stops {
-            let trips_url = format!(
-                "https://api.actransit.org/transit/stops/{}/tripstoday?token={}",
-                stop.stop_id, token
-            );
-            let trips: Vec<ActTrip> = client.get(trips_url).send().await?.json().await?;
-            let trips_by_id: HashMap<u32, ActTrip> =
-                trips
-                    .iter()
-                    .fold(HashMap::new(), |mut acc: HashMap<u32, ActTrip>, trip| {
-                        acc.insert(trip.trip_id, trip.clone());
-                        acc
-                    });
-            let prediction_url = format!(
-                "https://api.actransit.org/transit/stops/{}/predictions?token={}",
-                stop.stop_id, token
-            );
-            let predictions_check = client.get(prediction_url).send().await?;
-            if predictions_check.status() != 200 {
-                stops_info.push(StopInfo {
-                    route: route.0.to_string(),
-                    name: stop.name.clone(),
-                    direction: None,
-                    prediction: None,
-                });
-                continue;
-            }
-            let predictions: Vec<ActPrediction> = predictions_check.json().await?;
-            let mut pushed = false;
-            for prediction in &predictions {
-                if prediction.route_name != route.0 {
-                    continue;
-                }
-                if let Some(trip) = trips_by_id.get(&prediction.trip_id) {
-                    pushed = true;
-                    stops_info.push(StopInfo {
-                        route: route.0.to_string(),
-                        name: stop.name.clone(),
-                        direction: Some(trip.direction.clone()),
-                        prediction: Some(
-                            chrono::NaiveDateTime::parse_from_str(
-                                &prediction.predicted_departure,
-                                "%Y-%m-%dT%H:%M:%S",
-                            )
-                            .unwrap(),
-                        ),
-                    });
-                } else {
-                    panic!(
-                        "Stop {}: No trip info found for Trip ID {}",
-                        stop.stop_id, prediction.trip_id
-                    );
-                }
-            }
-            if !pushed {
-                stops_info.push(StopInfo {
-                    route: route.0.to_string(),
-                    name: stop.name.clone(),
-                    direction: None,
-                    prediction: None,
-                });
-            }
-        }
+    if cli.json {
+        let departures: Vec<Departure> = stops.iter().map(Departure::from).collect();
+        println!("{}", serde_json::to_string(&departures)?);
+        return Ok(());
     }
-    Ok(stops_info)
-}
 
-#[tokio::main]
-async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let act_stops = fetch_act().await?;
-    let bart_stops = fetch_bart().await?;
-    let stops = [act_stops, bart_stops].concat();
     for stop in stops {
         if let Some(prediction) = stop.prediction {
             println!(
@@ -0,0 +1,25 @@
+use reqwest::Client;
+
+use crate::error::FetchError;
+
+/// A single upcoming departure/arrival at a stop, normalized across agencies.
+#[derive(Clone, Debug)]
+pub struct StopInfo {
+    pub route: String,
+    pub name: String,
+    pub direction: Option<String>,
+    pub prediction: Option<chrono::NaiveDateTime>,
+}
+
+/// A source of live departure predictions for one transit agency.
+///
+/// Implementors own whatever route/stop list and credentials they need to
+/// talk to their agency's API; `main` just asks each provider to `fetch`
+/// against a shared `Client` and merges the results.
+#[async_trait::async_trait]
+pub trait TransitProvider {
+    async fn fetch(&self, client: &Client) -> Result<Vec<StopInfo>, FetchError>;
+
+    /// Short human-readable name, used in logging and error messages.
+    fn name(&self) -> &str;
+}
@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::{Json, Router};
+use reqwest::Client;
+
+use crate::provider::{StopInfo, TransitProvider};
+
+/// Shared state handed to every axum handler: the pooled HTTP client and the
+/// set of providers to poll on each request.
+pub struct AppState {
+    pub client: Client,
+    pub providers: Vec<Box<dyn TransitProvider>>,
+}
+
+/// Wire representation of a [`StopInfo`], with the prediction rendered as
+/// ISO-8601 and a `minutes_away` field computed relative to "now" at
+/// response time, rather than stored on the domain model.
+#[derive(Debug, serde::Serialize)]
+pub struct Departure {
+    pub route: String,
+    pub name: String,
+    pub direction: Option<String>,
+    pub prediction: Option<String>,
+    pub minutes_away: Option<i64>,
+}
+
+impl From<&StopInfo> for Departure {
+    fn from(stop: &StopInfo) -> Self {
+        let minutes_away = stop
+            .prediction
+            .map(|p| (p - chrono::Local::now().naive_local()).num_minutes());
+        Departure {
+            route: stop.route.clone(),
+            name: stop.name.clone(),
+            direction: stop.direction.clone(),
+            prediction: stop.prediction.map(|p| p.format("%Y-%m-%dT%H:%M:%S").to_string()),
+            minutes_away,
+        }
+    }
+}
+
+struct ApiError(Box<dyn std::error::Error>);
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (StatusCode::INTERNAL_SERVER_ERROR, self.0.to_string()).into_response()
+    }
+}
+
+async fn fetch_all(state: &AppState) -> Result<Vec<StopInfo>, ApiError> {
+    let mut stops = Vec::new();
+    for provider in &state.providers {
+        stops.extend(
+            provider
+                .fetch(&state.client)
+                .await
+                .map_err(|err| ApiError(Box::new(err)))?,
+        );
+    }
+    Ok(stops)
+}
+
+async fn departures(State(state): State<Arc<AppState>>) -> Result<Json<Vec<Departure>>, ApiError> {
+    let stops = fetch_all(&state).await?;
+    Ok(Json(stops.iter().map(Departure::from).collect()))
+}
+
+async fn departures_for_route(
+    State(state): State<Arc<AppState>>,
+    Path(route): Path<String>,
+) -> Result<Json<Vec<Departure>>, ApiError> {
+    let stops = fetch_all(&state).await?;
+    Ok(Json(
+        stops
+            .iter()
+            .filter(|stop| stop.route == route)
+            .map(Departure::from)
+            .collect(),
+    ))
+}
+
+/// Serve `GET /departures` and `GET /departures/:route` over HTTP until the
+/// process is killed.
+pub async fn run(state: AppState, port: u16) -> Result<(), Box<dyn std::error::Error>> {
+    let state = Arc::new(state);
+    let app = Router::new()
+        .route("/departures", get(departures))
+        .route("/departures/:route", get(departures_for_route))
+        .with_state(state);
+
+    let listener = tokio::net::TcpListener::bind(("0.0.0.0", port)).await?;
+    axum::serve(listener, app).await?;
+    Ok(())
+}
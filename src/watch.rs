@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use reqwest::Client;
+
+use crate::provider::{StopInfo, TransitProvider};
+
+/// A prediction has to shift by more than this, in either direction, before
+/// we report it as `Moved` rather than ignoring it as jitter.
+const MOVE_THRESHOLD_MINUTES: i64 = 2;
+
+/// How long an entry can go missing from a poll before we report it as
+/// `GONE` instead of assuming it's a transient gap in the feed.
+const DISAPPEAR_TIMEOUT: Duration = Duration::from_secs(180);
+
+type StopKey = (String, String, Option<String>);
+
+#[derive(Clone, Debug)]
+struct TrackedStop {
+    prediction: Option<chrono::NaiveDateTime>,
+    last_seen: Instant,
+}
+
+fn stop_key(stop: &StopInfo) -> StopKey {
+    (stop.route.clone(), stop.name.clone(), stop.direction.clone())
+}
+
+fn describe(key: &StopKey) -> String {
+    match &key.2 {
+        Some(direction) => format!("{} {}: {}", key.0, direction, key.1),
+        None => format!("{}: {}", key.0, key.1),
+    }
+}
+
+/// Poll every `providers` on `interval`, diffing each cycle's predictions
+/// against the last and printing only what changed: new upcoming
+/// departures, ones that shifted by more than [`MOVE_THRESHOLD_MINUTES`],
+/// and ones that have been missing longer than [`DISAPPEAR_TIMEOUT`].
+pub async fn run(
+    providers: &[Box<dyn TransitProvider>],
+    client: &Client,
+    interval: Duration,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut tracked: HashMap<StopKey, TrackedStop> = HashMap::new();
+
+    loop {
+        let mut seen_this_cycle: HashMap<StopKey, Option<chrono::NaiveDateTime>> = HashMap::new();
+        for provider in providers {
+            for stop in provider.fetch(client).await? {
+                seen_this_cycle.insert(stop_key(&stop), stop.prediction);
+            }
+        }
+
+        let now = Instant::now();
+
+        for (key, prediction) in &seen_this_cycle {
+            match tracked.get_mut(key) {
+                Some(existing) => {
+                    let moved = match (existing.prediction, prediction) {
+                        (Some(old), Some(new)) => {
+                            (*new - old).num_minutes().abs() > MOVE_THRESHOLD_MINUTES
+                        }
+                        (old, new) => old != *new,
+                    };
+                    if moved {
+                        println!("MOVED     {}", describe(key));
+                    }
+                    existing.prediction = *prediction;
+                    existing.last_seen = now;
+                }
+                None => {
+                    println!("APPEARED  {}", describe(key));
+                    tracked.insert(
+                        key.clone(),
+                        TrackedStop {
+                            prediction: *prediction,
+                            last_seen: now,
+                        },
+                    );
+                }
+            }
+        }
+
+        tracked.retain(|key, existing| {
+            if seen_this_cycle.contains_key(key) {
+                return true;
+            }
+            if now.duration_since(existing.last_seen) > DISAPPEAR_TIMEOUT {
+                println!("GONE      {}", describe(key));
+                false
+            } else {
+                true
+            }
+        });
+
+        tokio::time::sleep(interval).await;
+    }
+}